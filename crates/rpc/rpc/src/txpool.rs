@@ -1,7 +1,13 @@
-use std::{collections::BTreeMap, marker::PhantomData};
+use std::{
+    collections::BTreeMap,
+    marker::PhantomData,
+    sync::Arc,
+    time::{Duration, Instant},
+};
 
 use async_trait::async_trait;
-use jsonrpsee::core::RpcResult as Result;
+use jsonrpsee::{core::RpcResult as Result, proc_macros::rpc};
+use parking_lot::RwLock;
 use reth_primitives::{Address, TransactionSignedEcRecovered};
 use reth_rpc_api::TxPoolApiServer;
 use reth_rpc_types::{
@@ -12,6 +18,107 @@ use reth_rpc_types_compat::TransactionCompat;
 use reth_transaction_pool::{AllPoolTransactions, PoolTransaction, TransactionPool};
 use tracing::trace;
 
+/// Default time-to-live for the cached snapshots served by `txpool_content`, `txpool_inspect`
+/// and `txpool_status`.
+const DEFAULT_CACHE_TTL: Duration = Duration::from_millis(500);
+
+/// Number of senders reported in [`TxpoolLimitStatus::busiest_senders`].
+const BUSIEST_SENDERS_LIMIT: usize = 10;
+
+/// Capacity limits for a [`TransactionPool`], surfaced so `txpool_limit_status` reflects the
+/// pool's actual configuration instead of values duplicated into the RPC layer.
+///
+/// `TransactionPool` doesn't expose these directly yet; this bridges that gap until it does.
+pub trait PoolLimitInfo: TransactionPool {
+    /// The configured global transaction slot limit.
+    fn max_pool_size(&self) -> usize;
+    /// The configured per-sender transaction slot limit.
+    fn per_sender_limit(&self) -> usize;
+}
+
+/// Pool capacity and utilization, as reported by `txpool_limit_status`.
+#[derive(Debug, Clone, Default)]
+pub struct TxpoolLimitStatus {
+    /// Total number of pending and queued transactions currently in the pool.
+    pub total: usize,
+    /// The pool's configured global transaction slot limit.
+    pub max_size: usize,
+    /// The pool's configured per-sender transaction slot limit.
+    pub per_sender_limit: usize,
+    /// Number of distinct senders currently occupying at least one slot.
+    pub sender_count: usize,
+    /// The busiest senders by transaction count, descending, capped to
+    /// [`BUSIEST_SENDERS_LIMIT`] entries.
+    pub busiest_senders: Vec<(Address, usize)>,
+}
+
+/// Sorts `(sender, count)` pairs by descending count and truncates to `limit`, returning the
+/// total number of distinct senders alongside the truncated, sorted list.
+fn busiest_senders(counts: BTreeMap<Address, usize>, limit: usize) -> (usize, Vec<(Address, usize)>) {
+    let sender_count = counts.len();
+    let mut busiest: Vec<_> = counts.into_iter().collect();
+    busiest.sort_unstable_by(|(_, a), (_, b)| b.cmp(a));
+    busiest.truncate(limit);
+    (sender_count, busiest)
+}
+
+/// Groups `(sender, nonce, value)` entries by sender, stopping once `max_len` entries have been
+/// inserted so callers don't have to materialize more of the pool than was asked for.
+///
+/// `collected` is shared across multiple calls (e.g. pending then queued) so a single `max_len`
+/// bounds the combined total.
+fn group_with_limit<V>(
+    entries: impl IntoIterator<Item = (Address, String, V)>,
+    max_len: Option<usize>,
+    collected: &mut usize,
+) -> BTreeMap<Address, BTreeMap<String, V>> {
+    let mut grouped = BTreeMap::new();
+    let limit_reached = |collected: usize| max_len.is_some_and(|max_len| collected >= max_len);
+    if limit_reached(*collected) {
+        return grouped;
+    }
+    for (sender, nonce, value) in entries {
+        grouped.entry(sender).or_insert_with(BTreeMap::new).insert(nonce, value);
+        *collected += 1;
+        if limit_reached(*collected) {
+            break;
+        }
+    }
+    grouped
+}
+
+/// A cached, already-formatted view of the pool, built once and reused by `txpool_content` and
+/// `txpool_inspect` until it goes stale.
+///
+/// `content`/`inspect` are `Arc`-wrapped so cloning the snapshot out of the cache lock is a
+/// refcount bump regardless of which half a caller actually needs.
+#[derive(Clone, Default)]
+struct ContentSnapshot {
+    content: Arc<TxpoolContent>,
+    inspect: Arc<TxpoolInspect>,
+    fetched_at: Option<Instant>,
+}
+
+impl ContentSnapshot {
+    fn is_fresh(&self, ttl: Duration) -> bool {
+        self.fetched_at.is_some_and(|fetched_at| fetched_at.elapsed() < ttl)
+    }
+}
+
+/// A cached [`TxpoolStatus`], refreshed independently of [`ContentSnapshot`] since it only needs
+/// pool sizes, not a fully formatted view of every transaction.
+#[derive(Clone, Default)]
+struct StatusSnapshot {
+    status: TxpoolStatus,
+    fetched_at: Option<Instant>,
+}
+
+impl StatusSnapshot {
+    fn is_fresh(&self, ttl: Duration) -> bool {
+        self.fetched_at.is_some_and(|fetched_at| fetched_at.elapsed() < ttl)
+    }
+}
+
 /// `txpool` API implementation.
 ///
 /// This type provides the functionality for handling `txpool` related requests.
@@ -19,13 +126,34 @@ use tracing::trace;
 pub struct TxPoolApi<Pool, Eth> {
     /// An interface to interact with the pool
     pool: Pool,
+    /// Cached snapshot of `txpool_content`/`txpool_inspect`, shared across clones so concurrent
+    /// callers recompute it at most once per `cache_ttl`.
+    content_cache: Arc<RwLock<ContentSnapshot>>,
+    /// Cached `txpool_status`, refreshed independently of `content_cache` since it's much
+    /// cheaper to rebuild.
+    status_cache: Arc<RwLock<StatusSnapshot>>,
+    /// How long a cached snapshot is served before it's recomputed.
+    cache_ttl: Duration,
     _tx_resp_builder: PhantomData<Eth>,
 }
 
 impl<Pool, Eth> TxPoolApi<Pool, Eth> {
     /// Creates a new instance of `TxpoolApi`.
-    pub const fn new(pool: Pool) -> Self {
-        Self { pool, _tx_resp_builder: PhantomData }
+    pub fn new(pool: Pool) -> Self {
+        Self {
+            pool,
+            content_cache: Arc::new(RwLock::new(ContentSnapshot::default())),
+            status_cache: Arc::new(RwLock::new(StatusSnapshot::default())),
+            cache_ttl: DEFAULT_CACHE_TTL,
+            _tx_resp_builder: PhantomData,
+        }
+    }
+
+    /// Sets the time-to-live for the cached pool snapshots used to answer `txpool_content`,
+    /// `txpool_inspect` and `txpool_status`.
+    pub const fn with_cache_ttl(mut self, ttl: Duration) -> Self {
+        self.cache_ttl = ttl;
+        self
     }
 }
 
@@ -35,61 +163,73 @@ where
     // todo: make alloy_rpc_types_txpool::TxpoolContent generic over transaction
     Eth: TransactionCompat<Transaction = Transaction>,
 {
-    fn content(&self) -> TxpoolContent {
+    /// Builds the [`TxpoolContent`] response, capping the combined pending/queued entries to
+    /// `max_len` when set.
+    fn content(&self, max_len: Option<usize>) -> TxpoolContent {
+        Self::build_content(&self.pool.all_transactions(), max_len)
+    }
+
+    /// Builds [`TxpoolContent`] from an already-fetched [`AllPoolTransactions`].
+    fn build_content(
+        all: &AllPoolTransactions<Pool::Transaction>,
+        max_len: Option<usize>,
+    ) -> TxpoolContent {
+        let mut collected = 0usize;
+        let pending = group_with_limit(
+            all.pending.iter().map(|tx| {
+                (
+                    tx.transaction.sender(),
+                    tx.transaction.nonce().to_string(),
+                    Eth::from_recovered(tx.transaction.clone().into()),
+                )
+            }),
+            max_len,
+            &mut collected,
+        );
+        let queued = group_with_limit(
+            all.queued.iter().map(|tx| {
+                (
+                    tx.transaction.sender(),
+                    tx.transaction.nonce().to_string(),
+                    Eth::from_recovered(tx.transaction.clone().into()),
+                )
+            }),
+            max_len,
+            &mut collected,
+        );
+
+        TxpoolContent { pending, queued }
+    }
+
+    /// Builds the [`TxpoolContentFrom`] response for a single sender, converting only that
+    /// sender's transactions instead of building the response for every sender in the pool.
+    ///
+    /// The whole pool is still listed via `all_transactions()` and then filtered by sender.
+    fn content_from(&self, from: Address) -> TxpoolContentFrom {
         #[inline]
-        fn insert<Tx, Eth>(
-            tx: &Tx,
-            content: &mut BTreeMap<Address, BTreeMap<String, Eth::Transaction>>,
-        ) where
+        fn insert<Tx, Eth>(tx: &Tx, content: &mut BTreeMap<String, Eth::Transaction>)
+        where
             Tx: PoolTransaction,
             Eth: TransactionCompat<Transaction = Transaction>,
         {
-            content
-                .entry(tx.sender())
-                .or_default()
-                .insert(tx.nonce().to_string(), Eth::from_recovered(tx.clone().into()));
+            content.insert(tx.nonce().to_string(), Eth::from_recovered(tx.clone().into()));
         }
 
         let AllPoolTransactions { pending, queued } = self.pool.all_transactions();
 
-        let mut content = TxpoolContent::default();
-        for pending in pending {
+        let mut content = TxpoolContentFrom::default();
+        for pending in pending.iter().filter(|tx| tx.transaction.sender() == from) {
             insert::<_, Eth>(&pending.transaction, &mut content.pending);
         }
-        for queued in queued {
+        for queued in queued.iter().filter(|tx| tx.transaction.sender() == from) {
             insert::<_, Eth>(&queued.transaction, &mut content.queued);
         }
 
         content
     }
-}
-
-#[async_trait]
-impl<Pool, Eth> TxPoolApiServer for TxPoolApi<Pool, Eth>
-where
-    Pool: TransactionPool + 'static,
-    Eth: TransactionCompat<Transaction = Transaction> + 'static,
-{
-    /// Returns the number of transactions currently pending for inclusion in the next block(s), as
-    /// well as the ones that are being scheduled for future execution only.
-    /// Ref: [Here](https://geth.ethereum.org/docs/rpc/ns-txpool#txpool_status)
-    ///
-    /// Handler for `txpool_status`
-    async fn txpool_status(&self) -> Result<TxpoolStatus> {
-        trace!(target: "rpc::eth", "Serving txpool_status");
-        let all = self.pool.all_transactions();
-        Ok(TxpoolStatus { pending: all.pending.len() as u64, queued: all.queued.len() as u64 })
-    }
-
-    /// Returns a summary of all the transactions currently pending for inclusion in the next
-    /// block(s), as well as the ones that are being scheduled for future execution only.
-    ///
-    /// See [here](https://geth.ethereum.org/docs/rpc/ns-txpool#txpool_inspect) for more details
-    ///
-    /// Handler for `txpool_inspect`
-    async fn txpool_inspect(&self) -> Result<TxpoolInspect> {
-        trace!(target: "rpc::eth", "Serving txpool_inspect");
 
+    /// Builds [`TxpoolInspect`] from an already-fetched [`AllPoolTransactions`].
+    fn build_inspect(all: &AllPoolTransactions<Pool::Transaction>) -> TxpoolInspect {
         #[inline]
         fn insert<T: PoolTransaction>(
             tx: &T,
@@ -108,18 +248,88 @@ where
             );
         }
 
-        let AllPoolTransactions { pending, queued } = self.pool.all_transactions();
-
-        Ok(TxpoolInspect {
-            pending: pending.iter().fold(Default::default(), |mut acc, tx| {
+        TxpoolInspect {
+            pending: all.pending.iter().fold(Default::default(), |mut acc, tx| {
                 insert(&tx.transaction, &mut acc);
                 acc
             }),
-            queued: queued.iter().fold(Default::default(), |mut acc, tx| {
+            queued: all.queued.iter().fold(Default::default(), |mut acc, tx| {
                 insert(&tx.transaction, &mut acc);
                 acc
             }),
-        })
+        }
+    }
+
+    /// Returns the cached [`ContentSnapshot`], recomputing it from a single
+    /// `all_transactions()` call if it's older than `cache_ttl`.
+    fn snapshot(&self) -> ContentSnapshot {
+        let snapshot = self.content_cache.read().clone();
+        if snapshot.is_fresh(self.cache_ttl) {
+            return snapshot;
+        }
+
+        let mut cache = self.content_cache.write();
+        if cache.is_fresh(self.cache_ttl) {
+            return cache.clone();
+        }
+
+        let all = self.pool.all_transactions();
+        let snapshot = ContentSnapshot {
+            content: Arc::new(Self::build_content(&all, None)),
+            inspect: Arc::new(Self::build_inspect(&all)),
+            fetched_at: Some(Instant::now()),
+        };
+        *cache = snapshot.clone();
+        snapshot
+    }
+
+    /// Returns the cached [`TxpoolStatus`], recomputing it if it's older than `cache_ttl`.
+    ///
+    /// This only counts pool sizes, so it's refreshed independently of [`Self::snapshot`]
+    /// instead of piggy-backing on the more expensive content/inspect build.
+    fn status(&self) -> TxpoolStatus {
+        let snapshot = self.status_cache.read().clone();
+        if snapshot.is_fresh(self.cache_ttl) {
+            return snapshot.status;
+        }
+
+        let mut cache = self.status_cache.write();
+        if cache.is_fresh(self.cache_ttl) {
+            return cache.status.clone();
+        }
+
+        let all = self.pool.all_transactions();
+        let status = TxpoolStatus { pending: all.pending.len() as u64, queued: all.queued.len() as u64 };
+        *cache = StatusSnapshot { status: status.clone(), fetched_at: Some(Instant::now()) };
+        status
+    }
+}
+
+#[async_trait]
+impl<Pool, Eth> TxPoolApiServer for TxPoolApi<Pool, Eth>
+where
+    Pool: TransactionPool + 'static,
+    Eth: TransactionCompat<Transaction = Transaction> + 'static,
+{
+    /// Returns the number of transactions currently pending for inclusion in the next block(s), as
+    /// well as the ones that are being scheduled for future execution only.
+    /// Ref: [Here](https://geth.ethereum.org/docs/rpc/ns-txpool#txpool_status)
+    ///
+    /// Handler for `txpool_status`
+    async fn txpool_status(&self) -> Result<TxpoolStatus> {
+        trace!(target: "rpc::eth", "Serving txpool_status");
+        Ok(self.status())
+    }
+
+    /// Returns a summary of all the transactions currently pending for inclusion in the next
+    /// block(s), as well as the ones that are being scheduled for future execution only.
+    ///
+    /// See [here](https://geth.ethereum.org/docs/rpc/ns-txpool#txpool_inspect) for more details
+    ///
+    /// Handler for `txpool_inspect`
+    async fn txpool_inspect(&self) -> Result<TxpoolInspect> {
+        trace!(target: "rpc::eth", "Serving txpool_inspect");
+        Ok((*self.snapshot().inspect).clone())
     }
 
     /// Retrieves the transactions contained within the txpool, returning pending as well as queued
@@ -129,7 +339,7 @@ where
     /// Handler for `txpool_contentFrom`
     async fn txpool_content_from(&self, from: Address) -> Result<TxpoolContentFrom> {
         trace!(target: "rpc::eth", ?from, "Serving txpool_contentFrom");
-        Ok(self.content().remove_from(&from))
+        Ok(self.content_from(from))
     }
 
     /// Returns the details of all transactions currently pending for inclusion in the next
@@ -139,7 +349,121 @@ where
     /// Handler for `txpool_content`
     async fn txpool_content(&self) -> Result<TxpoolContent> {
         trace!(target: "rpc::eth", "Serving txpool_content");
-        Ok(self.content())
+        Ok((*self.snapshot().content).clone())
+    }
+}
+
+/// Extension methods for the `txpool` namespace, in addition to [`TxPoolApiServer`].
+#[rpc(server, namespace = "txpool")]
+#[async_trait]
+pub trait TxPoolExtApi {
+    /// Like `txpool_content`, but caps the combined pending/queued entries returned to
+    /// `max_len`, so operators can bound response size on a pool with a large backlog.
+    ///
+    /// Handler for `txpool_contentPaged`.
+    #[method(name = "contentPaged")]
+    async fn txpool_content_paged(&self, max_len: Option<usize>) -> Result<TxpoolContent>;
+
+    /// Returns pending transactions ordered by effective priority, i.e. the order a miner would
+    /// include them in the next block, optionally filtering out anything priced below
+    /// `min_effective_gas_price` at `base_fee`. Transactions whose max fee per gas is below
+    /// `base_fee` are always left out, whether or not a minimum is given.
+    ///
+    /// Handler for `txpool_contentByPriority`.
+    #[method(name = "contentByPriority")]
+    async fn txpool_priority_content(
+        &self,
+        base_fee: u64,
+        min_effective_gas_price: Option<u128>,
+    ) -> Result<Vec<TxpoolInspectSummary>>;
+}
+
+/// Pool limit/capacity methods for the `txpool` namespace, split out from [`TxPoolExtApi`] since
+/// they need [`PoolLimitInfo`] rather than plain [`TransactionPool`].
+#[rpc(server, namespace = "txpool")]
+#[async_trait]
+pub trait TxPoolLimitApi {
+    /// Returns the pool's current capacity utilization: total slot usage against the pool's
+    /// configured global limit, and a per-sender breakdown against its per-sender limit, so
+    /// operators can spot senders hogging the pool before transactions start getting evicted.
+    ///
+    /// Handler for `txpool_limitStatus`.
+    #[method(name = "limitStatus")]
+    async fn txpool_limit_status(&self) -> Result<TxpoolLimitStatus>;
+}
+
+/// A transaction's effective gas price at `base_fee`: `min(max_fee_per_gas, base_fee +
+/// max_priority_fee_per_gas)`, i.e. what the sender actually pays per unit of gas once included.
+fn effective_gas_price(base_fee: u64, effective_tip_per_gas: u128) -> u128 {
+    base_fee as u128 + effective_tip_per_gas
+}
+
+#[async_trait]
+impl<Pool, Eth> TxPoolExtApiServer for TxPoolApi<Pool, Eth>
+where
+    Pool: TransactionPool + 'static,
+    Eth: TransactionCompat<Transaction = Transaction> + 'static,
+{
+    async fn txpool_content_paged(&self, max_len: Option<usize>) -> Result<TxpoolContent> {
+        trace!(target: "rpc::eth", ?max_len, "Serving txpool_contentPaged");
+        Ok(self.content(max_len))
+    }
+
+    async fn txpool_priority_content(
+        &self,
+        base_fee: u64,
+        min_effective_gas_price: Option<u128>,
+    ) -> Result<Vec<TxpoolInspectSummary>> {
+        trace!(target: "rpc::eth", base_fee, ?min_effective_gas_price, "Serving txpool_contentByPriority");
+
+        let summaries = self
+            .pool
+            .best_transactions()
+            .filter_map(|tx| {
+                let price = effective_gas_price(base_fee, tx.effective_tip_per_gas(base_fee)?);
+                if min_effective_gas_price.is_some_and(|min_price| price < min_price) {
+                    return None;
+                }
+
+                let transaction: TransactionSignedEcRecovered = tx.transaction.clone().into();
+                Some(TxpoolInspectSummary {
+                    to: transaction.to(),
+                    value: transaction.value(),
+                    gas: transaction.gas_limit() as u128,
+                    gas_price: transaction.transaction.max_fee_per_gas(),
+                })
+            })
+            .collect();
+
+        Ok(summaries)
+    }
+}
+
+#[async_trait]
+impl<Pool, Eth> TxPoolLimitApiServer for TxPoolApi<Pool, Eth>
+where
+    Pool: PoolLimitInfo + 'static,
+    Eth: TransactionCompat<Transaction = Transaction> + 'static,
+{
+    async fn txpool_limit_status(&self) -> Result<TxpoolLimitStatus> {
+        trace!(target: "rpc::eth", "Serving txpool_limitStatus");
+
+        let AllPoolTransactions { pending, queued } = self.pool.all_transactions();
+        let total = pending.len() + queued.len();
+
+        let mut counts: BTreeMap<Address, usize> = BTreeMap::new();
+        for tx in pending.iter().chain(queued.iter()) {
+            *counts.entry(tx.transaction.sender()).or_default() += 1;
+        }
+        let (sender_count, busiest_senders) = busiest_senders(counts, BUSIEST_SENDERS_LIMIT);
+
+        Ok(TxpoolLimitStatus {
+            total,
+            max_size: self.pool.max_pool_size(),
+            per_sender_limit: self.pool.per_sender_limit(),
+            sender_count,
+            busiest_senders,
+        })
     }
 }
 
@@ -148,3 +472,69 @@ impl<Pool, Eth> std::fmt::Debug for TxPoolApi<Pool, Eth> {
         f.debug_struct("TxpoolApi").finish_non_exhaustive()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn group_with_limit_stops_once_max_len_is_reached() {
+        let a = Address::with_last_byte(1);
+        let b = Address::with_last_byte(2);
+        let entries =
+            vec![(a, "0".to_string(), 1u32), (a, "1".to_string(), 2u32), (b, "0".to_string(), 3u32)];
+
+        let mut collected = 0;
+        let grouped = group_with_limit(entries, Some(2), &mut collected);
+
+        assert_eq!(collected, 2);
+        assert_eq!(grouped.values().map(BTreeMap::len).sum::<usize>(), 2);
+    }
+
+    #[test]
+    fn group_with_limit_shares_the_counter_across_calls() {
+        let a = Address::with_last_byte(1);
+        let b = Address::with_last_byte(2);
+
+        let mut collected = 0;
+        let pending = group_with_limit(vec![(a, "0".to_string(), 1u32)], Some(1), &mut collected);
+        let queued = group_with_limit(vec![(b, "0".to_string(), 2u32)], Some(1), &mut collected);
+
+        assert_eq!(pending.values().map(BTreeMap::len).sum::<usize>(), 1);
+        assert!(queued.is_empty());
+    }
+
+    #[test]
+    fn effective_gas_price_is_base_fee_plus_tip() {
+        assert_eq!(effective_gas_price(100, 2), 102);
+        assert_eq!(effective_gas_price(0, 5), 5);
+    }
+
+    #[test]
+    fn snapshot_is_fresh_until_ttl_elapses() {
+        fn check(is_fresh: impl Fn(Duration) -> bool) {
+            assert!(is_fresh(Duration::from_millis(50)));
+            std::thread::sleep(Duration::from_millis(60));
+            assert!(!is_fresh(Duration::from_millis(50)));
+        }
+
+        let content = ContentSnapshot { fetched_at: Some(Instant::now()), ..Default::default() };
+        check(|ttl| content.is_fresh(ttl));
+
+        let status = StatusSnapshot { fetched_at: Some(Instant::now()), ..Default::default() };
+        check(|ttl| status.is_fresh(ttl));
+    }
+
+    #[test]
+    fn busiest_senders_sorts_descending_and_truncates() {
+        let a = Address::with_last_byte(1);
+        let b = Address::with_last_byte(2);
+        let c = Address::with_last_byte(3);
+
+        let counts = BTreeMap::from([(a, 3), (b, 7), (c, 1)]);
+        let (sender_count, top) = busiest_senders(counts, 2);
+
+        assert_eq!(sender_count, 3);
+        assert_eq!(top, vec![(b, 7), (a, 3)]);
+    }
+}